@@ -8,7 +8,7 @@ use crate::{
     shared::{field_rename_annotation, keyword_replace},
     GraphQLClientCodegenOptions,
 };
-use heck::SnakeCase;
+use heck::{CamelCase, SnakeCase};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use selection::*;
@@ -76,16 +76,17 @@ fn generate_variables_struct(
     }
 
     let variable_fields = operation.variables().map(generate_variable_struct_field);
-    let variable_defaults = operation.variables().map(|variable| {
+    let variable_defaults = operation.variables().filter_map(|variable| {
+        let body = render_variable_default(operation, variable)?;
         let method_name = format!("default_{}", variable.name());
         let method_name = Ident::new(&method_name, Span::call_site());
         let method_return_type = render_variable_field_type(variable);
 
-        quote!(
+        Some(quote!(
             pub fn #method_name() -> #method_return_type {
-                todo!()
+                #body
             }
-        )
+        ))
     });
 
     let variables_struct = quote!(
@@ -130,12 +131,124 @@ fn render_derives<'a>(derives: impl Iterator<Item = &'a str>) -> impl quote::ToT
     quote!(#[derive(#(#idents),*)])
 }
 
+/// Render a `#[deprecated]` attribute for a schema field or enum value carrying
+/// the `@deprecated` directive, using the `reason` argument as the note when the
+/// server supplied one.
+fn render_deprecation(reason: Option<&str>) -> TokenStream {
+    match reason {
+        Some(reason) => quote!(#[deprecated(note = #reason)]),
+        None => quote!(#[deprecated]),
+    }
+}
+
 fn render_variable_field_type(variable: VariableRef<'_>) -> TokenStream {
     let full_name = Ident::new(variable.type_name(), Span::call_site());
 
     decorate_type(&full_name, variable.type_qualifiers())
 }
 
+/// Render the body of a `default_<name>()` associated function from the schema
+/// default value attached to a variable.
+///
+/// Returns `None` when there is no default and the variable is non-nullable, so
+/// the caller can omit the method entirely rather than emit one it cannot
+/// honour.
+fn render_variable_default(
+    operation: &OperationRef<'_>,
+    variable: VariableRef<'_>,
+) -> Option<TokenStream> {
+    let nullable = !matches!(
+        variable.type_qualifiers().first(),
+        Some(GraphqlTypeQualifier::Required)
+    );
+
+    match variable.default() {
+        Some(value) => {
+            let schema = operation.schema();
+            let expr = render_default_value(value, schema, Some(variable.type_name()));
+            // A `null` default is already rendered as `None`; anything else in a
+            // nullable position needs wrapping to match `render_variable_field_type`.
+            if nullable && !matches!(value, graphql_parser::query::Value::Null) {
+                Some(quote!(Some(#expr)))
+            } else {
+                Some(quote!(#expr))
+            }
+        }
+        None if nullable => Some(quote!(None)),
+        None => None,
+    }
+}
+
+/// Render a GraphQL constant value as the equivalent Rust literal/expression.
+///
+/// `type_name` is the named type the value is constructed for. It lets us
+/// qualify enum variants (`Status::Active`), decide when an integer literal
+/// must be rendered as a float, and resolve the input type of an object default
+/// so nested objects keep their own struct name and stay exhaustive.
+fn render_default_value(
+    value: &graphql_parser::query::Value<'_, String>,
+    schema: SchemaRef<'_>,
+    type_name: Option<&str>,
+) -> TokenStream {
+    use graphql_parser::query::Value;
+
+    match value {
+        Value::Variable(_) => quote!(Default::default()),
+        Value::Int(n) => {
+            let n = n.as_i64().unwrap_or_default();
+            // `graphql_parser` keeps an integer literal as `Int` even when the
+            // declared type is `Float`, so coerce it to a float literal there.
+            if type_name == Some("Float") {
+                let n = n as f64;
+                quote!(#n)
+            } else {
+                quote!(#n)
+            }
+        }
+        Value::Float(f) => quote!(#f),
+        Value::String(s) => quote!(#s.to_string()),
+        Value::Boolean(b) => quote!(#b),
+        Value::Null => quote!(None),
+        Value::Enum(variant) => {
+            let ident = Ident::new(&variant.to_camel_case(), Span::call_site());
+            // Variants aren't glob-imported, so qualify with the enum type.
+            match type_name {
+                Some(name) => {
+                    let enum_ident = Ident::new(name, Span::call_site());
+                    quote!(#enum_ident::#ident)
+                }
+                None => quote!(#ident),
+            }
+        }
+        Value::List(values) => {
+            let items = values
+                .iter()
+                .map(|value| render_default_value(value, schema, type_name));
+            quote!(vec![#(#items),*])
+        }
+        Value::Object(object) => {
+            let struct_name = type_name.map(|name| Ident::new(name, Span::call_site()));
+            let input = type_name.and_then(|name| schema.find_input(name));
+            // Emit an assignment for every field on the input type, not just the
+            // keys present in the literal: a const object default may omit
+            // optional fields (implicitly null), and a non-exhaustive struct
+            // literal would not compile.
+            let assignments = input.into_iter().flat_map(|input| {
+                input.fields().map(move |field| {
+                    let snake_case_name = field.name().to_snake_case();
+                    let ident = Ident::new(&keyword_replace(&snake_case_name), Span::call_site());
+                    let rendered = match object.get(field.name()) {
+                        Some(value) => render_default_value(value, schema, Some(field.type_name())),
+                        None => quote!(None),
+                    };
+                    quote!(#ident: #rendered)
+                })
+            });
+            quote!(#struct_name { #(#assignments),* })
+        }
+    }
+}
+
 fn decorate_type(ident: &Ident, qualifiers: &[GraphqlTypeQualifier]) -> TokenStream {
     let mut qualified = quote!(#ident);
 
@@ -179,15 +292,34 @@ fn generate_input_object_definitions(
     all_used_types: &UsedTypes,
     options: &GraphQLClientCodegenOptions,
 ) -> Vec<TokenStream> {
+    let derives = render_derives(options.all_variable_derives());
+
     all_used_types
         .inputs(operation.schema())
         .map(|input| {
             let struct_name = Ident::new(input.name(), Span::call_site());
-            quote!(pub struct #struct_name;)
+            let fields = input.fields().map(generate_input_object_field);
+
+            quote! {
+                #derives
+                pub struct #struct_name {
+                    #(#fields,)*
+                }
+            }
         })
         .collect()
 }
 
+fn generate_input_object_field(field: InputFieldRef<'_>) -> TokenStream {
+    let snake_case_name = field.name().to_snake_case();
+    let ident = Ident::new(&keyword_replace(&snake_case_name), Span::call_site());
+    let annotation = field_rename_annotation(field.name(), &snake_case_name);
+    let full_name = Ident::new(field.type_name(), Span::call_site());
+    let r#type = decorate_type(&full_name, field.type_qualifiers());
+
+    quote!(#annotation pub #ident : #r#type)
+}
+
 fn generate_fragment_definitions(
     operation: &OperationRef<'_>,
     all_used_types: &UsedTypes,
@@ -217,6 +349,8 @@ fn render_object_like_struct(
     struct_name: &str,
     fields: &[TokenStream],
     variants: &[TokenStream],
+    connection: Option<&ConnectionShape>,
+    options: &GraphQLClientCodegenOptions,
 ) -> TokenStream {
     let (on_field, on_enum) = if variants.len() > 0 {
         let enum_name_str = format!("{}On", struct_name);
@@ -228,6 +362,7 @@ fn render_object_like_struct(
                 response_derives,
                 &enum_name_str,
                 variants,
+                options,
             )),
         )
     } else {
@@ -235,6 +370,7 @@ fn render_object_like_struct(
     };
 
     let struct_ident = Ident::new(struct_name, Span::call_site());
+    let connection_impl = render_connection_helpers(struct_name, connection, options);
 
     quote! {
         #response_derives
@@ -243,22 +379,94 @@ fn render_object_like_struct(
             #on_field
         }
 
+        #connection_impl
+
         #on_enum
     }
 }
 
+/// The resolved type names of a Relay Cursor Connection selection.
+///
+/// Produced by the selection renderer *after* it has confirmed the sub-selections
+/// match the connection shape — `edges { node, cursor }` and
+/// `pageInfo { hasNextPage, hasPreviousPage, startCursor, endCursor }` — so the
+/// generated helpers always reference structs that exist.
+pub(crate) struct ConnectionShape {
+    /// Generated struct name for the `node` selected under `edges`.
+    pub(crate) node_type: String,
+    /// Generated struct name for the `pageInfo` selection.
+    pub(crate) page_info_type: String,
+}
+
+/// When the connection codegen mode is enabled and the caller has recognised a
+/// Relay Cursor Connection, emit pagination helpers alongside the plain response
+/// struct so callers don't hand-roll the nested `Option<Vec<Option<Edge>>>`
+/// traversal.
+fn render_connection_helpers(
+    struct_name: &str,
+    connection: Option<&ConnectionShape>,
+    options: &GraphQLClientCodegenOptions,
+) -> Option<TokenStream> {
+    if !options.relay_connections() {
+        return None;
+    }
+
+    // Only emit the impl when the selection set was actually recognised as a
+    // connection; a mere `edges`/`page_info` field-name match is not enough, and
+    // generating against non-existent nested structs would break compilation.
+    let connection = connection?;
+
+    let struct_ident = Ident::new(struct_name, Span::call_site());
+    let node_ident = Ident::new(&connection.node_type, Span::call_site());
+    let page_info_ident = Ident::new(&connection.page_info_type, Span::call_site());
+
+    Some(quote! {
+        impl #struct_ident {
+            pub fn edges_iter(&self) -> impl Iterator<Item = &#node_ident> {
+                self.edges
+                    .iter()
+                    .flatten()
+                    .filter_map(|edge| edge.as_ref())
+                    .filter_map(|edge| edge.node.as_ref())
+            }
+
+            pub fn page_info(&self) -> &#page_info_ident {
+                &self.page_info
+            }
+
+            pub fn next_page_cursor(&self) -> Option<&str> {
+                if self.page_info.has_next_page {
+                    self.page_info.end_cursor.as_deref()
+                } else {
+                    None
+                }
+            }
+        }
+    })
+}
+
 fn render_union_enum(
     response_derives: &impl quote::ToTokens,
     enum_name: &str,
     variants: &[TokenStream],
+    options: &GraphQLClientCodegenOptions,
 ) -> TokenStream {
     let enum_ident = Ident::new(enum_name, Span::call_site());
 
+    // A catch-all variant keeps deserialization from hard-failing when the
+    // server reports a `__typename` this client wasn't compiled against.
+    let catch_all = if options.catch_all_variants() {
+        Some(quote!(#[serde(other)] Unknown,))
+    } else {
+        None
+    };
+
     quote! {
         #response_derives
         #[serde(tag = "__typename")]
         pub enum #enum_ident {
             #(#variants,)*
+            #catch_all
         }
     }
 }
@@ -0,0 +1,52 @@
+use crate::{
+    resolution::*,
+    shared::{field_rename_annotation, keyword_replace},
+    GraphQLClientCodegenOptions,
+};
+use heck::CamelCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// Render a Rust enum for every enum type used by the operation.
+pub(super) fn generate_enum_definitions(
+    operation: &OperationRef<'_>,
+    all_used_types: &UsedTypes,
+    options: &GraphQLClientCodegenOptions,
+) -> Vec<TokenStream> {
+    let response_derives = super::render_derives(options.all_response_derives());
+    let catch_all = options.catch_all_variants();
+
+    all_used_types
+        .enums(operation.schema())
+        .map(|r#enum| {
+            let enum_ident = Ident::new(r#enum.name(), Span::call_site());
+            let variants = r#enum.variants().map(|variant| {
+                let name = variant.name();
+                let camel_case_name = name.to_camel_case();
+                let variant_ident = Ident::new(&keyword_replace(&camel_case_name), Span::call_site());
+                let annotation = field_rename_annotation(name, &camel_case_name);
+                // Surface the schema's `@deprecated` directive so selecting a
+                // retired enum value raises a compiler warning.
+                let deprecation = variant.deprecation().map(super::render_deprecation);
+
+                quote!(#deprecation #annotation #variant_ident)
+            });
+
+            // A catch-all keeps deserialization from hard-failing when the
+            // server returns an enum value this client wasn't compiled against.
+            let other_variant = if catch_all {
+                Some(quote!(#[serde(other)] Other,))
+            } else {
+                None
+            };
+
+            quote! {
+                #response_derives
+                pub enum #enum_ident {
+                    #(#variants,)*
+                    #other_variant
+                }
+            }
+        })
+        .collect()
+}
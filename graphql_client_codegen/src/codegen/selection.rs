@@ -0,0 +1,139 @@
+use super::ConnectionShape;
+use crate::{
+    resolution::*,
+    shared::{field_rename_annotation, keyword_replace},
+    GraphQLClientCodegenOptions,
+};
+use heck::{CamelCase, SnakeCase};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// Render the `ResponseData` struct and every nested selection struct for the
+/// operation's root selection set.
+pub(super) fn render_response_data_fields(
+    operation: &OperationRef<'_>,
+    response_derives: &impl quote::ToTokens,
+    options: &GraphQLClientCodegenOptions,
+) -> TokenStream {
+    let mut definitions = Vec::new();
+    render_selection_set(
+        "ResponseData",
+        operation.selection(),
+        response_derives,
+        options,
+        &mut definitions,
+    );
+
+    quote!(#(#definitions)*)
+}
+
+/// Render a fragment's struct and its nested selections.
+pub(super) fn render_fragment(
+    fragment: &FragmentRef<'_>,
+    response_derives: &impl quote::ToTokens,
+    options: &GraphQLClientCodegenOptions,
+) -> TokenStream {
+    let mut definitions = Vec::new();
+    render_selection_set(
+        fragment.name(),
+        fragment.selection(),
+        response_derives,
+        options,
+        &mut definitions,
+    );
+
+    quote!(#(#definitions)*)
+}
+
+/// Render the struct for a single object-like selection set, recursing into any
+/// nested object selections and collecting every generated definition into
+/// `definitions`.
+fn render_selection_set<'a>(
+    struct_name: &str,
+    selection: impl Iterator<Item = SelectionRef<'a>>,
+    response_derives: &impl quote::ToTokens,
+    options: &GraphQLClientCodegenOptions,
+    definitions: &mut Vec<TokenStream>,
+) {
+    let selection: Vec<SelectionRef<'a>> = selection.collect();
+
+    let mut fields = Vec::with_capacity(selection.len());
+    for field in &selection {
+        let name = field.name();
+        let snake_case_name = name.to_snake_case();
+        let ident = Ident::new(&keyword_replace(&snake_case_name), Span::call_site());
+        let annotation = field_rename_annotation(name, &snake_case_name);
+        // Surface the schema's `@deprecated` directive on the generated field.
+        let deprecation = field.deprecation().map(super::render_deprecation);
+
+        // Nested object selections get their own struct, named by concatenating
+        // the field path so the connection helpers can reference them.
+        let type_ident = if field.has_subselection() {
+            let nested_name = format!("{}{}", struct_name, name.to_camel_case());
+            render_selection_set(
+                &nested_name,
+                field.subselection(),
+                response_derives,
+                options,
+                definitions,
+            );
+            Ident::new(&nested_name, Span::call_site())
+        } else {
+            Ident::new(field.field_type_name(), Span::call_site())
+        };
+        let r#type = super::decorate_type(&type_ident, field.type_qualifiers());
+
+        fields.push(quote!(#deprecation #annotation pub #ident: #r#type));
+    }
+
+    let connection = detect_connection_shape(struct_name, &selection, options);
+    definitions.push(super::render_object_like_struct(
+        response_derives,
+        struct_name,
+        &fields,
+        &[],
+        connection.as_ref(),
+        options,
+    ));
+}
+
+/// Recognise a Relay Cursor Connection selection and resolve the type names the
+/// generated helpers need.
+///
+/// Returns `Some` only when the connection codegen mode is on *and* the
+/// sub-selections actually match the shape — `edges { node, cursor }` and a
+/// `pageInfo` selecting `hasNextPage`, `hasPreviousPage`, `startCursor` and
+/// `endCursor` — so we never emit helpers against structs that were not
+/// generated.
+fn detect_connection_shape(
+    struct_name: &str,
+    selection: &[SelectionRef<'_>],
+    options: &GraphQLClientCodegenOptions,
+) -> Option<ConnectionShape> {
+    if !options.relay_connections() {
+        return None;
+    }
+
+    let edges = selection.iter().find(|field| field.name() == "edges")?;
+    let page_info = selection.iter().find(|field| field.name() == "pageInfo")?;
+
+    let edge_fields: Vec<_> = edges.subselection().collect();
+    let edges_ok = edge_fields.iter().any(|field| field.name() == "node")
+        && edge_fields.iter().any(|field| field.name() == "cursor");
+    if !edges_ok {
+        return None;
+    }
+
+    let page_info_fields: Vec<_> = page_info.subselection().collect();
+    let page_info_ok = ["hasNextPage", "hasPreviousPage", "startCursor", "endCursor"]
+        .iter()
+        .all(|expected| page_info_fields.iter().any(|field| field.name() == *expected));
+    if !page_info_ok {
+        return None;
+    }
+
+    Some(ConnectionShape {
+        node_type: format!("{}EdgesNode", struct_name),
+        page_info_type: format!("{}PageInfo", struct_name),
+    })
+}
@@ -0,0 +1,63 @@
+//! Configuration for the code generation, including the derive lists and the
+//! opt-in feature flags threaded through [`crate::codegen`].
+
+/// Used to configure code generation.
+#[derive(Debug, Default)]
+pub struct GraphQLClientCodegenOptions {
+    variable_derives: Vec<String>,
+    response_derives: Vec<String>,
+    /// Emit Relay Cursor Connection pagination helpers for connection-shaped
+    /// selections.
+    relay_connections: bool,
+    /// Emit a catch-all variant on enums and union enums so unknown server
+    /// values deserialize instead of erroring.
+    catch_all_variants: bool,
+}
+
+impl GraphQLClientCodegenOptions {
+    /// Create a new set of options with the defaults.
+    pub fn new() -> GraphQLClientCodegenOptions {
+        GraphQLClientCodegenOptions::default()
+    }
+
+    /// The derives applied to the generated response and fragment structs.
+    pub fn all_response_derives(&self) -> impl Iterator<Item = &str> {
+        std::iter::once("Deserialize").chain(self.response_derives.iter().map(String::as_str))
+    }
+
+    /// The derives applied to the generated `Variables` and input-object structs.
+    pub fn all_variable_derives(&self) -> impl Iterator<Item = &str> {
+        std::iter::once("Serialize").chain(self.variable_derives.iter().map(String::as_str))
+    }
+
+    /// Set the extra derives applied to the response structs.
+    pub fn set_response_derives(&mut self, derives: Vec<String>) {
+        self.response_derives = derives;
+    }
+
+    /// Set the extra derives applied to the variable and input-object structs.
+    pub fn set_variable_derives(&mut self, derives: Vec<String>) {
+        self.variable_derives = derives;
+    }
+
+    /// Whether Relay Cursor Connection pagination helpers should be generated.
+    pub fn relay_connections(&self) -> bool {
+        self.relay_connections
+    }
+
+    /// Enable or disable the Relay connection helpers.
+    pub fn set_relay_connections(&mut self, value: bool) {
+        self.relay_connections = value;
+    }
+
+    /// Whether enums and union enums should gain a catch-all variant so unknown
+    /// server values deserialize instead of erroring.
+    pub fn catch_all_variants(&self) -> bool {
+        self.catch_all_variants
+    }
+
+    /// Enable or disable the catch-all enum/union variant.
+    pub fn set_catch_all_variants(&mut self, value: bool) {
+        self.catch_all_variants = value;
+    }
+}